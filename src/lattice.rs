@@ -0,0 +1,35 @@
+use std::cmp::Ordering;
+
+/// A bounded lattice: a partial order equipped with a meet (greatest lower
+/// bound), a join (least upper bound), and distinguished bottom/top elements.
+///
+/// Modeled on the partial-order lattice utilities found in timely/differential
+/// dataflow and Materialize's `order.rs`. `less_equal` and `less_than` are
+/// defined in terms of the type's existing [`PartialOrd`] impl, so `meet`,
+/// `join` and the order agree by construction as long as `PartialOrd` is
+/// implemented consistently with them.
+pub trait Lattice: PartialOrd + Sized {
+    /// The bottom element, i.e. `Self::BOTTOM.less_equal(x)` for all `x`.
+    const BOTTOM: Self;
+    /// The top element, i.e. `x.less_equal(Self::TOP)` for all `x`.
+    const TOP: Self;
+
+    /// Greatest lower bound of `self` and `other`.
+    fn meet(&self, other: &Self) -> Self;
+
+    /// Least upper bound of `self` and `other`.
+    fn join(&self, other: &Self) -> Self;
+
+    /// `self <= other` under the lattice's partial order.
+    fn less_equal(&self, other: &Self) -> bool {
+        matches!(
+            self.partial_cmp(other),
+            Some(Ordering::Less | Ordering::Equal)
+        )
+    }
+
+    /// `self < other` under the lattice's partial order.
+    fn less_than(&self, other: &Self) -> bool {
+        self.partial_cmp(other) == Some(Ordering::Less)
+    }
+}
@@ -1,4 +1,5 @@
 pub mod endpoint;
+pub mod lattice;
 
 use std::{
     cmp::Ordering,
@@ -6,6 +7,7 @@ use std::{
 };
 
 pub use endpoint::Endpoint;
+pub use lattice::Lattice;
 
 #[cfg(feature = "serde")]
 mod _interval {
@@ -165,3 +167,34 @@ where
         Self::new(self.left.max(rhs.left), self.right.min(rhs.right))
     }
 }
+
+/// `meet` is the intersection (same as `*`); `join` is the convex hull, i.e.
+/// the smallest interval containing both operands.
+///
+/// Note `join` is *not* the union: joining two disjoint intervals spans the
+/// gap between them.
+///
+/// ```
+/// use interva::{Interval, Lattice};
+/// assert!(Interval::closed(1, 3).join(&Interval::closed(2, 4)) == Interval::closed(1, 4));
+/// // disjoint intervals: the join bridges the gap rather than union-ing them
+/// assert!(Interval::closed(1, 2).join(&Interval::closed(5, 6)) == Interval::closed(1, 6));
+/// assert!(Interval::closed(1, 3).meet(&Interval::open(2, 4)) == Interval::lorc(2, 3));
+/// assert!(Interval::<i32>::BOTTOM == Interval::EMPTY);
+/// assert!(Interval::<i32>::TOP == Interval::ALL);
+/// ```
+impl<T: Eq + Copy> Lattice for Interval<T>
+where
+    Endpoint<T>: Ord,
+{
+    const BOTTOM: Self = Self::EMPTY;
+    const TOP: Self = Self::ALL;
+
+    fn meet(&self, other: &Self) -> Self {
+        *self * *other
+    }
+
+    fn join(&self, other: &Self) -> Self {
+        Self::new(self.left.min(other.left), self.right.max(other.right))
+    }
+}